@@ -1,9 +1,288 @@
 use tauri::Manager;
 
+/// Accelerator used to summon the quick-capture writer window before the user has
+/// rebound it, or when no saved shortcut config exists yet.
+const DEFAULT_CAPTURE_SHORTCUT: &str = "CmdOrCtrl+Shift+Space";
+
+/// Persisted global-shortcut preference, stored as `shortcut.json` in the app config dir.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct ShortcutConfig {
+    accelerator: String,
+}
+
+impl Default for ShortcutConfig {
+    fn default() -> Self {
+        Self {
+            accelerator: DEFAULT_CAPTURE_SHORTCUT.to_string(),
+        }
+    }
+}
+
+fn shortcut_config_path(app: &tauri::AppHandle) -> Result<std::path::PathBuf, String> {
+    let dir = app.path().app_config_dir().map_err(|e| e.to_string())?;
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir.join("shortcut.json"))
+}
+
+fn load_shortcut_config(app: &tauri::AppHandle) -> ShortcutConfig {
+    shortcut_config_path(app)
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|raw| serde_json::from_str(&raw).ok())
+        .unwrap_or_default()
+}
+
+fn save_shortcut_config(app: &tauri::AppHandle, config: &ShortcutConfig) -> Result<(), String> {
+    let path = shortcut_config_path(app)?;
+    let raw = serde_json::to_string_pretty(config).map_err(|e| e.to_string())?;
+    std::fs::write(path, raw).map_err(|e| e.to_string())
+}
+
+/// Registers `accelerator` as the quick-capture shortcut. Only once that succeeds does
+/// it drop `previous` (if any and different), so a bad rebind - a malformed or
+/// already-reserved accelerator - can't leave the user with no capture shortcut at all
+/// for the rest of the session.
+fn register_capture_shortcut(
+    app: &tauri::AppHandle,
+    accelerator: &str,
+    previous: Option<&str>,
+) -> Result<(), String> {
+    use tauri_plugin_global_shortcut::{GlobalShortcutExt, ShortcutState};
+
+    if previous == Some(accelerator) {
+        return Ok(());
+    }
+
+    let manager = app.global_shortcut();
+
+    manager
+        .on_shortcut(accelerator, move |app, _shortcut, event| {
+            if event.state() == ShortcutState::Pressed {
+                if let Some(window) = app.get_webview_window("main") {
+                    let _ = window.show();
+                    let _ = window.set_focus();
+                }
+            }
+        })
+        .map_err(|e| e.to_string())?;
+
+    if let Some(previous) = previous {
+        if previous != accelerator {
+            let _ = manager.unregister(previous);
+        }
+    }
+
+    Ok(())
+}
+
+/// Rebinds the quick-capture global shortcut at runtime and persists the choice so
+/// it's restored on the next launch.
+#[tauri::command]
+fn set_capture_shortcut(app: tauri::AppHandle, accelerator: String) -> Result<(), String> {
+    let previous = load_shortcut_config(&app).accelerator;
+    register_capture_shortcut(&app, &accelerator, Some(&previous))?;
+    save_shortcut_config(&app, &ShortcutConfig { accelerator })
+}
+
+/// Toggles the app between a normal Dock/⌘-Tab presence (`Regular`) and a lightweight
+/// menu-bar-only presence (`Accessory`) where the Dock icon and app switcher entry are
+/// hidden, leaving the tray icon and the capture shortcut as the ways to reach the writer.
+#[cfg(target_os = "macos")]
+#[tauri::command]
+fn set_activation_policy(app: tauri::AppHandle, accessory: bool) -> Result<(), String> {
+    let policy = if accessory {
+        tauri::ActivationPolicy::Accessory
+    } else {
+        tauri::ActivationPolicy::Regular
+    };
+    app.set_activation_policy(policy).map_err(|e| e.to_string())
+}
+
+#[cfg(not(target_os = "macos"))]
+#[tauri::command]
+fn set_activation_policy(_app: tauri::AppHandle, _accessory: bool) -> Result<(), String> {
+    Err("activation policy is only configurable on macOS".to_string())
+}
+
+// Re-applies the backdrop material at runtime so it can follow the editor's theme.
+#[tauri::command]
+fn set_window_material(
+    app: tauri::AppHandle,
+    material: String,
+    tint: Option<[u8; 4]>,
+) -> Result<(), String> {
+    let window = app
+        .get_webview_window("main")
+        .ok_or_else(|| "main window not found".to_string())?;
+
+    #[cfg(target_os = "macos")]
+    {
+        use window_vibrancy::{apply_vibrancy, NSVisualEffectMaterial};
+
+        let material = match material.as_str() {
+            "hud" => NSVisualEffectMaterial::HudWindow,
+            "sidebar" => NSVisualEffectMaterial::Sidebar,
+            "under-window" => NSVisualEffectMaterial::UnderWindowBackground,
+            "fullscreen-ui" => NSVisualEffectMaterial::FullScreenUI,
+            other => return Err(format!("unknown macOS material: {other}")),
+        };
+
+        return apply_vibrancy(&window, material, None, Some(12.0)).map_err(|e| e.to_string());
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        use window_vibrancy::{apply_acrylic, apply_blur, apply_mica};
+
+        let tint = tint.map(|[r, g, b, a]| (r, g, b, a));
+
+        return match material.as_str() {
+            "mica" => apply_mica(&window, None).map_err(|e| e.to_string()),
+            "acrylic" => {
+                apply_acrylic(&window, tint.or(Some((18, 18, 18, 125)))).map_err(|e| e.to_string())
+            }
+            "blur" => {
+                apply_blur(&window, tint.or(Some((18, 18, 18, 125)))).map_err(|e| e.to_string())
+            }
+            other => Err(format!("unknown Windows material: {other}")),
+        };
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    {
+        let _ = (window, material, tint);
+        Err("window material switching is not supported on this platform".to_string())
+    }
+}
+
+// Toggles the unified transparent-window model (see `build_transparent_main_window`)
+// on in place of the per-platform vibrancy/decoration handling below.
+fn transparent_window_enabled() -> bool {
+    matches!(std::env::var("WRITER_TRANSPARENT_WINDOW").as_deref(), Ok("1"))
+}
+
+// Rebuilds "main" from its own tauri.conf.json entry with transparency layered on top.
+fn build_transparent_main_window(
+    app: &tauri::AppHandle,
+) -> tauri::Result<tauri::WebviewWindow> {
+    if let Some(existing) = app.get_webview_window("main") {
+        existing.close()?;
+    }
+
+    let window_config = app
+        .config()
+        .app
+        .windows
+        .iter()
+        .find(|w| w.label == "main")
+        .cloned()
+        .ok_or(tauri::Error::WindowNotFound)?;
+
+    tauri::WebviewWindowBuilder::from_config(app, &window_config)?
+        .transparent(true)
+        .decorations(false)
+        .build()
+}
+
+// Applies the window's translucency treatment; called at startup and again whenever
+// the tray restores the window, since hiding/showing can drop the platform backdrop.
+fn apply_window_backdrop(window: &tauri::WebviewWindow) {
+    if transparent_window_enabled() {
+        // The window and webview are already transparent - it was built that way by
+        // `build_transparent_main_window` - so there's no platform backdrop to apply
+        // here, just decorations, which stay off uniformly across platforms.
+        let _ = window.set_decorations(false);
+
+        #[cfg(target_os = "linux")]
+        {
+            // Compositor blur is outside our control on Linux, so we
+            // degrade gracefully to a transparent-but-unblurred window
+            // rather than faking a blur effect.
+            log::info!("Transparent window mode on Linux: no compositor blur available");
+
+            if cfg!(debug_assertions) {
+                let _ = window.set_zoom(1.3);
+            }
+        }
+    } else {
+        #[cfg(target_os = "macos")]
+        {
+            use window_vibrancy::{apply_vibrancy, NSVisualEffectMaterial};
+
+            // Apply vibrancy effect for macOS - gives a polished native feel
+            if let Err(e) =
+                apply_vibrancy(window, NSVisualEffectMaterial::HudWindow, None, Some(12.0))
+            {
+                log::warn!("Failed to apply vibrancy: {}", e);
+            }
+        }
+
+        #[cfg(target_os = "windows")]
+        {
+            use window_vibrancy::{apply_acrylic, apply_blur, apply_mica};
+
+            // Keep decorations enabled on Windows for native title bar controls
+            let _ = window.set_decorations(true);
+
+            // Mirror the macOS vibrancy treatment: prefer mica (Windows 11),
+            // fall back to acrylic, and finally a flat blur with a dark tint
+            // when neither is available. Acrylic/mica are known to tank resize
+            // performance on some Windows builds, so once mica succeeds we skip
+            // the heavier fallbacks entirely instead of layering them.
+            if let Err(e) = apply_mica(window, None) {
+                log::warn!("Failed to apply mica backdrop: {}", e);
+
+                if let Err(e) = apply_acrylic(window, Some((18, 18, 18, 125))) {
+                    log::warn!("Failed to apply acrylic backdrop: {}", e);
+
+                    if let Err(e) = apply_blur(window, Some((18, 18, 18, 125))) {
+                        log::warn!("Failed to apply blur backdrop: {}", e);
+                    } else {
+                        log::info!("Applied blur backdrop");
+                    }
+                } else {
+                    log::info!("Applied acrylic backdrop");
+                }
+            } else {
+                log::info!("Applied mica backdrop");
+            }
+        }
+
+        #[cfg(target_os = "linux")]
+        {
+            // Disable decorations on Linux for custom title bar
+            let _ = window.set_decorations(false);
+
+            // Adjust zoom for better readability on Linux in debug mode
+            if cfg!(debug_assertions) {
+                let _ = window.set_zoom(1.3);
+            }
+        }
+    }
+}
+
+/// Brings the main window back from the tray: restores the geometry saved by
+/// `tauri_plugin_window_state`, shows and focuses the window, then reapplies the
+/// platform backdrop since hiding/showing can drop it.
+fn restore_main_window(window: &tauri::WebviewWindow) {
+    use tauri_plugin_window_state::{StateFlags, WindowExt};
+
+    let _ = window.restore_state(StateFlags::all());
+    let _ = window.show();
+    let _ = window.set_focus();
+    apply_window_backdrop(window);
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_window_state::Builder::new().build())
+        .plugin(tauri_plugin_global_shortcut::Builder::new().build())
+        .invoke_handler(tauri::generate_handler![
+            set_window_material,
+            set_capture_shortcut,
+            set_activation_policy
+        ])
         .setup(|app| {
             if cfg!(debug_assertions) {
                 app.handle().plugin(
@@ -13,36 +292,85 @@ pub fn run() {
                 )?;
             }
 
-            // Platform-specific window configuration
-            if let Some(window) = app.get_webview_window("main") {
-                #[cfg(target_os = "macos")]
-                {
-                    use window_vibrancy::{apply_vibrancy, NSVisualEffectMaterial};
-
-                    // Apply vibrancy effect for macOS - gives a polished native feel
-                    if let Err(e) =
-                        apply_vibrancy(&window, NSVisualEffectMaterial::HudWindow, None, Some(12.0))
-                    {
-                        log::warn!("Failed to apply vibrancy: {}", e);
-                    }
-                }
+            // Summon the quick-capture window from anywhere without focusing the app
+            // first, using whatever accelerator the user last rebound it to.
+            let shortcut_config = load_shortcut_config(&app.handle());
+            if let Err(e) =
+                register_capture_shortcut(&app.handle(), &shortcut_config.accelerator, None)
+            {
+                log::warn!(
+                    "Failed to register capture shortcut {}: {}",
+                    shortcut_config.accelerator,
+                    e
+                );
+            }
+
+            // System tray: show/hide, new document, and quit, so the writer keeps
+            // running in the background and stays reachable (including in macOS
+            // accessory mode, via `set_activation_policy`) after the window is closed.
+            {
+                use tauri::menu::{Menu, MenuItem};
+                use tauri::tray::TrayIconBuilder;
+
+                let show_hide = MenuItem::with_id(app, "show_hide", "Show/Hide", true, None::<&str>)?;
+                let new_document = MenuItem::with_id(app, "new_document", "New Document", true, None::<&str>)?;
+                let quit = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
+                let tray_menu = Menu::with_items(app, &[&show_hide, &new_document, &quit])?;
 
-                #[cfg(target_os = "windows")]
-                {
-                    // Keep decorations enabled on Windows for native title bar controls
-                    let _ = window.set_decorations(true);
+                let mut tray = TrayIconBuilder::new().menu(&tray_menu).tooltip("writer");
+                if let Some(icon) = app.default_window_icon().cloned() {
+                    tray = tray.icon(icon);
                 }
+                tray.on_menu_event(|app, event| match event.id.as_ref() {
+                    "show_hide" => {
+                        if let Some(window) = app.get_webview_window("main") {
+                            if window.is_visible().unwrap_or(false) {
+                                let _ = window.hide();
+                            } else {
+                                restore_main_window(&window);
+                            }
+                        }
+                    }
+                    "new_document" => {
+                        if let Some(window) = app.get_webview_window("main") {
+                            restore_main_window(&window);
+                            let _ = window.emit("writer://new-document", ());
+                        }
+                    }
+                    "quit" => app.exit(0),
+                    _ => {}
+                })
+                .build(app)?;
+            }
+
+            // Platform-specific window configuration
+            let window = if transparent_window_enabled() {
+                Some(build_transparent_main_window(&app.handle())?)
+            } else {
+                app.get_webview_window("main")
+            };
 
-                #[cfg(target_os = "linux")]
-                {
-                    // Disable decorations on Linux for custom title bar
-                    let _ = window.set_decorations(false);
+            if let Some(window) = window {
+                apply_window_backdrop(&window);
 
-                    // Adjust zoom for better readability on Linux in debug mode
-                    if cfg!(debug_assertions) {
-                        let _ = window.set_zoom(1.3);
+                // Hide to the tray instead of exiting on close or minimizing to the
+                // taskbar/dock, so the writer keeps running for the global shortcut
+                // and tray menu to summon it again. Tauri has no dedicated minimize
+                // event - the OS reports it as a Resized event - so a minimize is
+                // caught by checking `is_minimized()` there and hiding instead.
+                let window_ = window.clone();
+                window.on_window_event(move |event| match event {
+                    tauri::WindowEvent::CloseRequested { api, .. } => {
+                        api.prevent_close();
+                        let _ = window_.hide();
                     }
-                }
+                    tauri::WindowEvent::Resized(_) => {
+                        if window_.is_minimized().unwrap_or(false) {
+                            let _ = window_.hide();
+                        }
+                    }
+                    _ => {}
+                });
             }
 
             Ok(())